@@ -2,17 +2,28 @@ use std::io;
 use rand::Rng;
 use std::cmp::Ordering;
 
+const LOWER_BOUND: u32 = 1;
+const UPPER_BOUND: u32 = 100;
+const MAX_ATTEMPTS: u32 = 10;
+
+// Picks the secret number from an inclusive range so the caller never has to
+// remember to add 1 to the upper bound.
+fn make_secret(low: u32, high: u32) -> u32 {
+    rand::thread_rng().gen_range(low..=high)
+}
+
 fn main() {
     println!("Guess the number!");
 
-    // gen_range() was updated in newer rand versions and the syntax (a..b) is used instead of (a, b).
-    // There is an issue already opened for this on the book repository.
-    let secret_number = rand::thread_rng().gen_range(1..101);
-    
+    let secret_number = make_secret(LOWER_BOUND, UPPER_BOUND);
+
     // Although in the book this line is removed, I will maintain it.
     println!("The secret number is {}.", secret_number);
 
+    let mut attempts = 0;
+
     loop {
+        println!("Attempt {} of {}", attempts + 1, MAX_ATTEMPTS);
         println!("Please input your guess:");
 
         let mut guess = String::new();
@@ -21,21 +32,44 @@ fn main() {
             .read_line(&mut guess)
             .expect("Failed to read line!!");
     
-        let guess: u32 = match guess.trim().parse() {
+        let raw_input = guess.trim();
+
+        let guess: u32 = match raw_input.parse() {
             Ok(num) => num,
-            Err(_) => continue,
+            Err(_) => {
+                println!(
+                    "Please type a whole number between {} and {}, not '{}'.",
+                    LOWER_BOUND, UPPER_BOUND, raw_input
+                );
+                continue;
+            }
         };
-    
+
+        if guess < LOWER_BOUND || guess > UPPER_BOUND {
+            println!(
+                "{} is out of range, please type a number between {} and {}.",
+                guess, LOWER_BOUND, UPPER_BOUND
+            );
+            continue;
+        }
+
         println!("You guessed: {}", guess);
-    
+
+        attempts += 1;
+
         match guess.cmp(&secret_number) {
             Ordering::Less => println!("Too small!"),
             Ordering::Greater => println!("Too big!"),
             Ordering::Equal => {
-                println!("You win!");
+                println!("You won in {} guesses!", attempts);
                 break;
             }
         }
+
+        if attempts >= MAX_ATTEMPTS {
+            println!("You ran out of attempts! The secret number was {}.", secret_number);
+            break;
+        }
     }
 
 }
@@ -3,9 +3,13 @@ fn main() {
     let single_word = first_word(&test_string);
 
     println!("{}", single_word);
+
+    let literal_word = first_word("hello slices");
+
+    println!("{}", literal_word);
 }
 
-fn first_word(s: &String) -> &str {
+fn first_word(s: &str) -> &str {
     let bytes = s.as_bytes();
 
     for (i, &item) in bytes.iter().enumerate() {